@@ -1,6 +1,9 @@
+use flate2::read::MultiGzDecoder;
 use polars::prelude::*;
 use std::fs::File;
-use std::path::Path;
+use std::io::Read;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
 use std::time::Duration;
 use thiserror::Error;
 
@@ -18,6 +21,18 @@ pub enum ConversionError {
     #[error("Invalid compression level: {0}")]
     InvalidCompressionLevel(String),
 
+    #[error("Invalid schema: {0}")]
+    InvalidSchema(String),
+
+    #[error("Unknown column for bloom filter: {0}")]
+    UnknownColumn(String),
+
+    #[error("Invalid bloom filter false-positive probability: {0}")]
+    InvalidBloomFilterFpp(String),
+
+    #[error("Ambiguous input/output: {0}")]
+    AmbiguousIo(String),
+
     #[error("IO error: {0}")]
     Io(#[from] std::io::Error),
 
@@ -77,6 +92,90 @@ impl Compression {
             }
         }
     }
+
+    /// Parse a compression spec of the form `algo` or `algo:level`, e.g. `zstd`, `zstd:9`,
+    /// `gzip:6`. Rejects a level on algorithms that don't take one (`snappy`, `lz4`,
+    /// `uncompressed`) and validates the level against the algorithm's allowed range.
+    pub fn parse_spec(spec: &str) -> Result<Compression> {
+        let (name, level) = match spec.split_once(':') {
+            Some((name, level)) => (name, Some(level)),
+            None => (spec, None),
+        };
+
+        let compression = match name.to_lowercase().as_str() {
+            "uncompressed" => Compression::Uncompressed,
+            "snappy" => Compression::Snappy,
+            "gzip" => Compression::Gzip(None),
+            "lz4" => Compression::Lz4,
+            "zstd" => Compression::Zstd(None),
+            "brotli" => Compression::Brotli(None),
+            other => {
+                return Err(ConversionError::InvalidCompressionLevel(format!(
+                    "unknown compression algorithm {other:?}"
+                )))
+            }
+        };
+
+        let Some(level) = level else {
+            return Ok(compression);
+        };
+        let level: u32 = level.parse().map_err(|_| {
+            ConversionError::InvalidCompressionLevel(format!("invalid level {level:?}"))
+        })?;
+
+        match compression {
+            Compression::Gzip(_) => {
+                let level = u8::try_from(level).map_err(|_| {
+                    ConversionError::InvalidCompressionLevel(format!("invalid level {level}"))
+                })?;
+                GzipLevel::try_new(level)
+                    .map_err(|e| ConversionError::InvalidCompressionLevel(e.to_string()))?;
+                Ok(Compression::Gzip(Some(level)))
+            }
+            Compression::Zstd(_) => {
+                ZstdLevel::try_new(level as i32)
+                    .map_err(|e| ConversionError::InvalidCompressionLevel(e.to_string()))?;
+                Ok(Compression::Zstd(Some(level as i32)))
+            }
+            Compression::Brotli(_) => {
+                BrotliLevel::try_new(level)
+                    .map_err(|e| ConversionError::InvalidCompressionLevel(e.to_string()))?;
+                Ok(Compression::Brotli(Some(level)))
+            }
+            Compression::Uncompressed | Compression::Snappy | Compression::Lz4 => {
+                Err(ConversionError::InvalidCompressionLevel(format!(
+                    "{name} does not accept a compression level"
+                )))
+            }
+        }
+    }
+}
+
+/// How a CSV input stream is compressed, for transparent decompression
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CsvCompression {
+    /// Detect from the input file's extension (`.gz`, `.zst`), falling back to `None`
+    Auto,
+    /// Input is raw, uncompressed CSV
+    None,
+    /// Input is gzip-compressed
+    Gzip,
+    /// Input is zstd-compressed
+    Zstd,
+}
+
+impl CsvCompression {
+    /// Resolve `Auto` against a file's extension; other variants pass through unchanged
+    fn resolve(self, path: &Path) -> CsvCompression {
+        match self {
+            CsvCompression::Auto => match path.extension().and_then(|e| e.to_str()) {
+                Some("gz") => CsvCompression::Gzip,
+                Some("zst") => CsvCompression::Zstd,
+                _ => CsvCompression::None,
+            },
+            other => other,
+        }
+    }
 }
 
 /// Configuration options for CSV to Parquet conversion
@@ -90,6 +189,11 @@ pub struct ConversionOptions {
     pub quote_char: Option<u8>,
     /// Number of rows to scan for schema inference (None = scan all)
     pub infer_schema_rows: Option<usize>,
+    /// Path to a schema override file (`column_name: dtype` per line) whose
+    /// columns bypass inference; other columns are still inferred
+    pub schema_overrides: Option<PathBuf>,
+    /// Compression codec of the input CSV stream itself (e.g. `.csv.gz`)
+    pub input_compression: CsvCompression,
     /// Compression algorithm for Parquet output
     pub compression: Compression,
     /// Row group size (None = single row group)
@@ -102,6 +206,16 @@ pub struct ConversionOptions {
     pub statistics: bool,
     /// Enable parallel Parquet writing
     pub parallel: bool,
+    /// Stream the conversion via a `LazyFrame`/`sink_parquet` instead of materializing
+    /// the whole `DataFrame` in memory, for larger-than-memory inputs
+    pub streaming: bool,
+    /// Columns to write Parquet bloom filters for, speeding up point lookups
+    pub bloom_filter_columns: Vec<String>,
+    /// False-positive probability for bloom filters (0.01 when `None`)
+    pub bloom_filter_fpp: Option<f64>,
+    /// Columns to partition the output by, Hive-style (`key=value/` directories under
+    /// the output path), one Parquet file per distinct combination of values
+    pub partition_by: Vec<String>,
 }
 
 impl Default for ConversionOptions {
@@ -111,53 +225,252 @@ impl Default for ConversionOptions {
             delimiter: b',',
             quote_char: Some(b'"'),
             infer_schema_rows: Some(1000),
+            schema_overrides: None,
+            input_compression: CsvCompression::Auto,
             compression: Compression::Zstd(None),
             row_group_size: Some(500_000),
             n_threads: None,
             low_memory: false,
             statistics: true,
             parallel: true,
+            streaming: false,
+            bloom_filter_columns: Vec::new(),
+            bloom_filter_fpp: None,
+            partition_by: Vec::new(),
         }
     }
 }
 
+/// Where the CSV input comes from
+#[derive(Debug, Clone)]
+pub enum InputSource {
+    /// Read from a file on disk
+    Path(PathBuf),
+    /// Read from standard input (e.g. the Unix pipeline convention of `-`)
+    Stdin,
+}
+
+/// Where the Parquet output goes
+#[derive(Debug, Clone)]
+pub enum OutputSink {
+    /// Write to a file on disk
+    Path(PathBuf),
+    /// Write to standard output
+    Stdout,
+}
+
 /// Statistics returned after conversion
 #[derive(Debug, Clone)]
 pub struct ConversionStats {
-    /// Number of rows processed
-    pub rows_processed: usize,
+    /// Number of rows processed (`None` when streaming mode can't report a count cheaply)
+    pub rows_processed: Option<usize>,
     /// Size of output file in bytes
     pub output_size: u64,
     /// Time taken for conversion
     pub duration: Duration,
+    /// Number of Hive-partition files written (`None` when `partition_by` is empty)
+    pub partitions_written: Option<usize>,
 }
 
-/// Convert a CSV file to Parquet format
+/// Convert CSV input to Parquet output
 pub fn convert_csv_to_parquet(
-    input_path: &Path,
-    output_path: &Path,
+    input: &InputSource,
+    output: &OutputSink,
     options: &ConversionOptions,
 ) -> Result<ConversionStats> {
     let start = std::time::Instant::now();
 
-    // Read CSV file
-    let mut df = read_csv(input_path, options)?;
+    if options.streaming {
+        if !options.partition_by.is_empty() {
+            return Err(ConversionError::AmbiguousIo(
+                "--partition-by is not supported together with --streaming".to_string(),
+            ));
+        }
+
+        if !options.bloom_filter_columns.is_empty() {
+            return Err(ConversionError::AmbiguousIo(
+                "--bloom-filter is not supported together with --streaming".to_string(),
+            ));
+        }
+
+        let (InputSource::Path(input_path), OutputSink::Path(output_path)) = (input, output)
+        else {
+            return Err(ConversionError::AmbiguousIo(
+                "--streaming requires file paths for both input and output, not stdin/stdout"
+                    .to_string(),
+            ));
+        };
+
+        if options.input_compression.resolve(input_path) != CsvCompression::None {
+            return Err(ConversionError::AmbiguousIo(
+                "--streaming does not support compressed CSV input (.gz/.zst); decompress \
+                 the file first or drop --streaming"
+                    .to_string(),
+            ));
+        }
+
+        stream_csv_to_parquet(input_path, output_path, options)?;
+        let output_size = std::fs::metadata(output_path)?.len();
+        let rows_processed = parquet_row_count(output_path).ok();
+
+        return Ok(ConversionStats {
+            rows_processed,
+            output_size,
+            duration: start.elapsed(),
+            partitions_written: None,
+        });
+    }
+
+    let mut df = match input {
+        InputSource::Path(path) => read_csv(path, options)?,
+        InputSource::Stdin => {
+            let stdin = std::io::stdin();
+            read_csv_from_reader(stdin.lock(), options)?
+        }
+    };
     let rows_processed = df.height();
 
-    // Write Parquet file
-    let output_size = write_parquet(&mut df, output_path, options)?;
+    if !options.partition_by.is_empty() {
+        let OutputSink::Path(output_dir) = output else {
+            return Err(ConversionError::AmbiguousIo(
+                "--partition-by requires a directory output path, not stdout".to_string(),
+            ));
+        };
+
+        let (partitions_written, output_size) = write_partitioned_parquet(&mut df, output_dir, options)?;
+
+        return Ok(ConversionStats {
+            rows_processed: Some(rows_processed),
+            output_size,
+            duration: start.elapsed(),
+            partitions_written: Some(partitions_written),
+        });
+    }
 
-    let duration = start.elapsed();
+    let output_size = match output {
+        OutputSink::Path(path) => write_parquet(&mut df, File::create(path)?, options)?,
+        OutputSink::Stdout => {
+            let stdout = std::io::stdout();
+            write_parquet(&mut df, stdout.lock(), options)?
+        }
+    };
 
     Ok(ConversionStats {
-        rows_processed,
+        rows_processed: Some(rows_processed),
         output_size,
-        duration,
+        duration: start.elapsed(),
+        partitions_written: None,
     })
 }
 
-/// Read CSV file with specified options
-fn read_csv(path: &Path, options: &ConversionOptions) -> Result<DataFrame> {
+/// Split a DataFrame by the distinct value tuples of `partition_by` and write one
+/// Parquet file per partition under a Hive-style `key=value/` directory layout,
+/// dropping the partition columns from the written data
+fn write_partitioned_parquet(
+    df: &mut DataFrame,
+    output_dir: &Path,
+    options: &ConversionOptions,
+) -> Result<(usize, u64)> {
+    let parts = df
+        .partition_by(options.partition_by.clone(), true)
+        .map_err(|e| ConversionError::ParquetWrite(e.to_string()))?;
+
+    let partitions_written = parts.len();
+    let mut total_bytes = 0u64;
+
+    for part in parts {
+        let mut dir = output_dir.to_path_buf();
+        for column in &options.partition_by {
+            let value = part.column(column)?.get(0)?;
+            // `str_value` gives a string value bare; `to_string` would wrap it in quotes
+            dir.push(format!("{column}={}", hive_escape_path_segment(&value.str_value())));
+        }
+        std::fs::create_dir_all(&dir)?;
+
+        let mut part = part.drop_many(&options.partition_by);
+        let file_path = dir.join("data.parquet");
+        total_bytes += write_parquet(&mut part, File::create(file_path)?, options)?;
+    }
+
+    Ok((partitions_written, total_bytes))
+}
+
+/// Percent-encode a partition value for safe use as a single `key=value` path segment,
+/// Hive-style. Anything other than ASCII alphanumerics/`_`/`-` is escaped, so values
+/// containing `/` or `..` can't create unintended directory levels or escape the output dir
+fn hive_escape_path_segment(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for byte in value.bytes() {
+        match byte {
+            b'a'..=b'z' | b'A'..=b'Z' | b'0'..=b'9' | b'_' | b'-' => escaped.push(byte as char),
+            _ => escaped.push_str(&format!("%{byte:02X}")),
+        }
+    }
+    escaped
+}
+
+/// Stream a CSV file straight to Parquet via a `LazyFrame` and `sink_parquet`, never
+/// materializing the whole `DataFrame` in memory — the path for larger-than-memory files.
+/// Compressed input isn't supported here since `LazyCsvReader` scans directly from the
+/// file path; `convert_csv_to_parquet` rejects that combination before calling in here.
+/// `n_threads`/`low_memory` are honored the same as the non-streaming path (see
+/// `build_csv_read_options`), since `LazyCsvReader` exposes the same knobs.
+fn stream_csv_to_parquet(input_path: &Path, output_path: &Path, options: &ConversionOptions) -> Result<()> {
+    let mut lazy = LazyCsvReader::new(input_path)
+        .with_has_header(options.has_header)
+        .with_infer_schema_length(options.infer_schema_rows)
+        .with_separator(options.delimiter)
+        .with_low_memory(options.low_memory);
+
+    if let Some(threads) = options.n_threads {
+        lazy = lazy.with_n_threads(Some(threads));
+    }
+
+    lazy = if let Some(quote) = options.quote_char {
+        lazy.with_quote_char(Some(quote))
+    } else {
+        lazy.with_quote_char(None)
+    };
+
+    if let Some(schema_path) = &options.schema_overrides {
+        let schema = parse_schema_file(schema_path)?;
+        lazy = lazy.with_schema_overwrite(Some(Arc::new(schema)));
+    }
+
+    let lazy_frame = lazy
+        .finish()
+        .map_err(|e| ConversionError::CsvRead(e.to_string()))?;
+
+    let parquet_options = ParquetWriteOptions {
+        compression: options.compression.to_parquet_compression()?,
+        statistics: if options.statistics {
+            StatisticsOptions::full()
+        } else {
+            StatisticsOptions::empty()
+        },
+        row_group_size: options.row_group_size,
+        data_page_size: None,
+    };
+
+    lazy_frame
+        .sink_parquet(output_path, parquet_options)
+        .map_err(|e| ConversionError::ParquetWrite(e.to_string()))?;
+
+    Ok(())
+}
+
+/// Read the row count back out of a Parquet file's footer metadata, for conversions
+/// (like streaming) that never hold the full row count in memory
+fn parquet_row_count(path: &Path) -> Result<usize> {
+    let file = File::open(path)?;
+    ParquetReader::new(file)
+        .num_rows()
+        .map_err(|e| ConversionError::ParquetWrite(e.to_string()))
+}
+
+/// Build the `CsvReadOptions` shared by every CSV source (file path, decompressed
+/// buffer, or stdin) from the conversion options
+fn build_csv_read_options(options: &ConversionOptions) -> Result<CsvReadOptions> {
     let mut csv_options = CsvReadOptions::default()
         .with_has_header(options.has_header)
         .with_infer_schema_length(options.infer_schema_rows)
@@ -177,18 +490,132 @@ fn read_csv(path: &Path, options: &ConversionOptions) -> Result<DataFrame> {
 
     csv_options = csv_options.with_low_memory(options.low_memory);
 
-    let df = csv_options
-        .try_into_reader_with_file_path(Some(path.to_path_buf()))
-        .map_err(|e| ConversionError::CsvRead(e.to_string()))?
-        .finish()
-        .map_err(|e| ConversionError::CsvRead(e.to_string()))?;
+    if let Some(schema_path) = &options.schema_overrides {
+        let schema = parse_schema_file(schema_path)?;
+        csv_options = csv_options.with_schema_overwrite(Some(Arc::new(schema)));
+    }
+
+    Ok(csv_options)
+}
+
+/// Read CSV file with specified options
+fn read_csv(path: &Path, options: &ConversionOptions) -> Result<DataFrame> {
+    let csv_options = build_csv_read_options(options)?;
+
+    let df = match options.input_compression.resolve(path) {
+        CsvCompression::None => csv_options
+            .try_into_reader_with_file_path(Some(path.to_path_buf()))
+            .map_err(|e| ConversionError::CsvRead(e.to_string()))?
+            .finish()
+            .map_err(|e| ConversionError::CsvRead(e.to_string()))?,
+        codec => {
+            let decompressed = decompress(File::open(path)?, codec)?;
+            csv_options
+                .into_reader_with_file_handle(std::io::Cursor::new(decompressed))
+                .finish()
+                .map_err(|e| ConversionError::CsvRead(e.to_string()))?
+        }
+    };
 
     Ok(df)
 }
 
-/// Write DataFrame to Parquet file
-fn write_parquet(df: &mut DataFrame, path: &Path, options: &ConversionOptions) -> Result<u64> {
-    let file = File::create(path)?;
+/// Read CSV from an arbitrary reader (e.g. stdin), fully buffering it first since,
+/// unlike a file path, a stream like stdin can't be re-read from the start
+fn read_csv_from_reader<R: Read>(mut reader: R, options: &ConversionOptions) -> Result<DataFrame> {
+    let csv_options = build_csv_read_options(options)?;
+
+    let mut buf = Vec::new();
+    reader.read_to_end(&mut buf)?;
+
+    // `Auto` detection relies on a file extension, which a stream doesn't have
+    let codec = match options.input_compression {
+        CsvCompression::Auto => CsvCompression::None,
+        explicit => explicit,
+    };
+    if codec != CsvCompression::None {
+        buf = decompress(std::io::Cursor::new(buf), codec)?;
+    }
+
+    csv_options
+        .into_reader_with_file_handle(std::io::Cursor::new(buf))
+        .finish()
+        .map_err(|e| ConversionError::CsvRead(e.to_string()))
+}
+
+/// Fully decompress a gzip or zstd stream into memory
+///
+/// The codec is decided up front from the filename/flag rather than by peeking at the
+/// stream, since the decompressed CSV can't be re-read from the start once consumed
+fn decompress<R: Read>(source: R, codec: CsvCompression) -> Result<Vec<u8>> {
+    let mut buf = Vec::new();
+
+    match codec {
+        CsvCompression::Gzip => {
+            MultiGzDecoder::new(source).read_to_end(&mut buf)?;
+        }
+        CsvCompression::Zstd => {
+            zstd::stream::read::Decoder::new(source)?.read_to_end(&mut buf)?;
+        }
+        CsvCompression::None | CsvCompression::Auto => {
+            unreachable!("codec is resolved before calling decompress")
+        }
+    }
+
+    Ok(buf)
+}
+
+/// Parse a schema override file of `column_name: dtype` lines into a Polars schema
+fn parse_schema_file(path: &Path) -> Result<Schema> {
+    let contents = std::fs::read_to_string(path)?;
+
+    let mut schema = Schema::with_capacity(contents.lines().count());
+    for line in contents.lines() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+
+        let (name, dtype) = line.split_once(':').ok_or_else(|| {
+            ConversionError::InvalidSchema(format!("expected 'column: dtype', got {line:?}"))
+        })?;
+        let dtype = parse_dtype(dtype.trim()).ok_or_else(|| {
+            ConversionError::InvalidSchema(format!("unknown dtype {:?}", dtype.trim()))
+        })?;
+
+        schema.with_column(name.trim().into(), dtype);
+    }
+
+    Ok(schema)
+}
+
+/// Map a schema-file dtype name to a Polars `DataType`
+fn parse_dtype(s: &str) -> Option<DataType> {
+    Some(match s.to_lowercase().as_str() {
+        "str" | "string" | "utf8" => DataType::String,
+        "i8" => DataType::Int8,
+        "i16" => DataType::Int16,
+        "i32" => DataType::Int32,
+        "i64" | "int" => DataType::Int64,
+        "u8" => DataType::UInt8,
+        "u16" => DataType::UInt16,
+        "u32" => DataType::UInt32,
+        "u64" => DataType::UInt64,
+        "f32" => DataType::Float32,
+        "f64" | "float" => DataType::Float64,
+        "bool" | "boolean" => DataType::Boolean,
+        "date" => DataType::Date,
+        "datetime" => DataType::Datetime(TimeUnit::Microseconds, None),
+        _ => return None,
+    })
+}
+
+/// Write a DataFrame to Parquet, to any sink that implements `Write` (a file or stdout)
+fn write_parquet<W: std::io::Write>(
+    df: &mut DataFrame,
+    sink: W,
+    options: &ConversionOptions,
+) -> Result<u64> {
     let compression = options.compression.to_parquet_compression()?;
 
     let statistics = if options.statistics {
@@ -197,7 +624,7 @@ fn write_parquet(df: &mut DataFrame, path: &Path, options: &ConversionOptions) -
         StatisticsOptions::empty()
     };
 
-    let mut writer = ParquetWriter::new(file)
+    let mut writer = ParquetWriter::new(sink)
         .with_compression(compression)
         .with_statistics(statistics)
         .set_parallel(options.parallel);
@@ -206,6 +633,38 @@ fn write_parquet(df: &mut DataFrame, path: &Path, options: &ConversionOptions) -
         writer = writer.with_row_group_size(Some(row_group_size));
     }
 
+    if !options.bloom_filter_columns.is_empty() {
+        for column in &options.bloom_filter_columns {
+            df.column(column)
+                .map_err(|_| ConversionError::UnknownColumn(column.clone()))?;
+        }
+
+        let fpp = options.bloom_filter_fpp.unwrap_or(0.01);
+        if !(fpp > 0.0 && fpp < 1.0) {
+            return Err(ConversionError::InvalidBloomFilterFpp(format!(
+                "fpp must be in (0, 1), got {fpp}"
+            )));
+        }
+
+        // `with_bloom_filter_options` alone is a single on/off switch for the whole file, so
+        // build a per-field overwrite list to scope the filter to just the requested columns
+        let bloom_filter = BloomFilterOptions { fpp, ndv: None };
+        let field_overwrites = df
+            .get_column_names()
+            .into_iter()
+            .map(|name| ParquetFieldOverwrites {
+                bloom_filter_properties: if options.bloom_filter_columns.iter().any(|c| c == name.as_str()) {
+                    Some(Some(bloom_filter.clone()))
+                } else {
+                    Some(None)
+                },
+                ..Default::default()
+            })
+            .collect();
+
+        writer = writer.with_field_overwrites(field_overwrites);
+    }
+
     let bytes_written = writer
         .finish(df)
         .map_err(|e| ConversionError::ParquetWrite(e.to_string()))?;
@@ -231,4 +690,302 @@ mod tests {
         assert!(Compression::Snappy.to_parquet_compression().is_ok());
         assert!(Compression::Zstd(Some(3)).to_parquet_compression().is_ok());
     }
+
+    #[test]
+    fn test_compression_parse_spec() {
+        assert!(matches!(
+            Compression::parse_spec("zstd").unwrap(),
+            Compression::Zstd(None)
+        ));
+        assert!(matches!(
+            Compression::parse_spec("zstd:9").unwrap(),
+            Compression::Zstd(Some(9))
+        ));
+        assert!(matches!(
+            Compression::parse_spec("gzip:6").unwrap(),
+            Compression::Gzip(Some(6))
+        ));
+        assert!(Compression::parse_spec("snappy:5").is_err());
+        assert!(Compression::parse_spec("gzip:999").is_err());
+        assert!(Compression::parse_spec("not-a-codec").is_err());
+    }
+
+    #[test]
+    fn test_gzip_level_out_of_range_does_not_wrap() {
+        // 256 wraps to 0u8 if cast before validation; must be rejected, not silently accepted
+        assert!(Compression::parse_spec("gzip:256").is_err());
+    }
+
+    #[test]
+    fn test_csv_compression_auto_detect() {
+        assert_eq!(
+            CsvCompression::Auto.resolve(Path::new("data.csv.gz")),
+            CsvCompression::Gzip
+        );
+        assert_eq!(
+            CsvCompression::Auto.resolve(Path::new("data.csv.zst")),
+            CsvCompression::Zstd
+        );
+        assert_eq!(
+            CsvCompression::Auto.resolve(Path::new("data.csv")),
+            CsvCompression::None
+        );
+    }
+
+    #[test]
+    fn test_parse_dtype() {
+        assert_eq!(parse_dtype("str"), Some(DataType::String));
+        assert_eq!(parse_dtype("i64"), Some(DataType::Int64));
+        assert_eq!(parse_dtype("f64"), Some(DataType::Float64));
+        assert_eq!(parse_dtype("not-a-type"), None);
+    }
+
+    #[test]
+    fn test_hive_escape_path_segment() {
+        assert_eq!(hive_escape_path_segment("2024"), "2024");
+        assert_eq!(hive_escape_path_segment("a/b"), "a%2Fb");
+        assert_eq!(hive_escape_path_segment(".."), "%2E%2E");
+    }
+
+    #[test]
+    fn test_schema_override_changes_column_dtype() {
+        let dir = std::env::temp_dir().join("csv2parquet_test_schema_override");
+        std::fs::create_dir_all(&dir).unwrap();
+        let csv_path = dir.join("input.csv");
+        let schema_path = dir.join("schema.txt");
+        // `zip` would infer as an integer column by default; the override forces it to stay text
+        std::fs::write(&csv_path, "zip,name\n02138,alice\n94103,bob\n").unwrap();
+        std::fs::write(&schema_path, "zip: string\n").unwrap();
+
+        let options = ConversionOptions {
+            schema_overrides: Some(schema_path),
+            ..Default::default()
+        };
+        let df = read_csv(&csv_path, &options).unwrap();
+
+        assert_eq!(df.column("zip").unwrap().dtype(), &DataType::String);
+        assert_eq!(
+            df.column("zip").unwrap().str().unwrap().get(0),
+            Some("02138")
+        );
+
+        std::fs::remove_dir_all(&dir).ok();
+    }
+
+    #[test]
+    fn test_gzip_input_round_trip() {
+        use std::io::Write as _;
+
+        let dir = std::env::temp_dir().join("csv2parquet_test_gzip_round_trip");
+        std::fs::create_dir_all(&dir).unwrap();
+        let csv_gz_path = dir.join("input.csv.gz");
+
+        let mut encoder =
+            flate2::write::GzEncoder::new(Vec::new(), flate2::Compression::default());
+        encoder
+            .write_all(b"id,name\n1,alice\n2,bob\n3,carol\n")
+            .unwrap();
+        std::fs::write(&csv_gz_path, encoder.finish().unwrap()).unwrap();
+
+        let df = read_csv(&csv_gz_path, &ConversionOptions::default()).unwrap();
+
+        assert_eq!(df.height(), 3);
+        assert_eq!(df.get_column_names(), vec!["id", "name"]);
+
+        std::fs::remove_dir_all(&dir).ok();
+    }
+
+    #[test]
+    fn test_stdin_stdout_round_trip() {
+        let input = std::io::Cursor::new(b"id,name\n1,alice\n2,bob\n".to_vec());
+        let mut df = read_csv_from_reader(input, &ConversionOptions::default()).unwrap();
+        assert_eq!(df.height(), 2);
+
+        let mut output = Vec::new();
+        write_parquet(&mut df, &mut output, &ConversionOptions::default()).unwrap();
+
+        let read_back = ParquetReader::new(std::io::Cursor::new(output))
+            .finish()
+            .unwrap();
+        assert_eq!(read_back.height(), 2);
+        assert_eq!(read_back.get_column_names(), vec!["id", "name"]);
+    }
+
+    #[test]
+    fn test_partitioned_output_directory_layout() {
+        let dir = std::env::temp_dir().join("csv2parquet_test_partition_layout");
+        std::fs::remove_dir_all(&dir).ok();
+        std::fs::create_dir_all(&dir).unwrap();
+
+        let mut df = df![
+            "year" => [2023, 2023, 2024],
+            "amount" => [10.0, 20.0, 30.0],
+        ]
+        .unwrap();
+
+        let options = ConversionOptions {
+            partition_by: vec!["year".to_string()],
+            ..Default::default()
+        };
+        let (partitions_written, _) = write_partitioned_parquet(&mut df, &dir, &options).unwrap();
+
+        assert_eq!(partitions_written, 2);
+        assert!(dir.join("year=2023").join("data.parquet").is_file());
+        assert!(dir.join("year=2024").join("data.parquet").is_file());
+
+        // The partition column itself shouldn't be duplicated inside the partition's data
+        let part_df = ParquetReader::new(File::open(dir.join("year=2023/data.parquet")).unwrap())
+            .finish()
+            .unwrap();
+        assert_eq!(part_df.get_column_names(), vec!["amount"]);
+
+        std::fs::remove_dir_all(&dir).ok();
+    }
+
+    #[test]
+    fn test_bloom_filter_fpp_out_of_range_is_rejected() {
+        let df = df!["id" => [1i64, 2, 3]].unwrap();
+
+        for fpp in [0.0, -0.1, 1.0, 1.5] {
+            let options = ConversionOptions {
+                bloom_filter_columns: vec!["id".to_string()],
+                bloom_filter_fpp: Some(fpp),
+                ..Default::default()
+            };
+            let result = write_parquet(&mut df.clone(), &mut Vec::new(), &options);
+            assert!(
+                matches!(result, Err(ConversionError::InvalidBloomFilterFpp(_))),
+                "fpp {fpp} should have been rejected"
+            );
+        }
+    }
+
+    #[test]
+    fn test_bloom_filter_scoped_to_requested_columns() {
+        let df = df![
+            "id" => (0..2000i64).collect::<Vec<_>>(),
+            "name" => (0..2000).map(|i| format!("name-{i}")).collect::<Vec<_>>(),
+        ]
+        .unwrap();
+
+        let mut buf_none = Vec::new();
+        write_parquet(&mut df.clone(), &mut buf_none, &ConversionOptions::default()).unwrap();
+
+        let mut buf_one = Vec::new();
+        write_parquet(
+            &mut df.clone(),
+            &mut buf_one,
+            &ConversionOptions {
+                bloom_filter_columns: vec!["id".to_string()],
+                ..Default::default()
+            },
+        )
+        .unwrap();
+
+        let mut buf_all = Vec::new();
+        write_parquet(
+            &mut df.clone(),
+            &mut buf_all,
+            &ConversionOptions {
+                bloom_filter_columns: vec!["id".to_string(), "name".to_string()],
+                ..Default::default()
+            },
+        )
+        .unwrap();
+
+        // A filter on one column should grow the file a little; filtering both columns
+        // should grow it further still. If the filter were applied globally regardless of
+        // `bloom_filter_columns` (the bug being regression-tested here), `buf_one` and
+        // `buf_all` would come out the same size.
+        assert!(
+            buf_one.len() > buf_none.len(),
+            "requesting a bloom filter should grow the file"
+        );
+        assert!(
+            buf_all.len() > buf_one.len(),
+            "a bloom filter scoped to both columns should be larger than scoped to just one"
+        );
+    }
+
+    #[test]
+    fn test_streaming_rejects_compressed_input() {
+        let options = ConversionOptions {
+            streaming: true,
+            ..Default::default()
+        };
+        let result = convert_csv_to_parquet(
+            &InputSource::Path(PathBuf::from("data.csv.gz")),
+            &OutputSink::Path(PathBuf::from("/tmp/csv2parquet-unused.parquet")),
+            &options,
+        );
+        assert!(matches!(result, Err(ConversionError::AmbiguousIo(_))));
+    }
+
+    #[test]
+    fn test_streaming_rejects_bloom_filter() {
+        let options = ConversionOptions {
+            streaming: true,
+            bloom_filter_columns: vec!["id".to_string()],
+            ..Default::default()
+        };
+        let result = convert_csv_to_parquet(
+            &InputSource::Path(PathBuf::from("data.csv")),
+            &OutputSink::Path(PathBuf::from("/tmp/csv2parquet-unused.parquet")),
+            &options,
+        );
+        assert!(matches!(result, Err(ConversionError::AmbiguousIo(_))));
+    }
+
+    #[test]
+    fn test_streaming_round_trip() {
+        let dir = std::env::temp_dir().join("csv2parquet_test_streaming_round_trip");
+        std::fs::create_dir_all(&dir).unwrap();
+        let input_path = dir.join("input.csv");
+        let output_path = dir.join("output.parquet");
+        std::fs::write(&input_path, "id,name\n1,alice\n2,bob\n3,carol\n").unwrap();
+
+        let options = ConversionOptions {
+            streaming: true,
+            ..Default::default()
+        };
+        let stats = convert_csv_to_parquet(
+            &InputSource::Path(input_path),
+            &OutputSink::Path(output_path.clone()),
+            &options,
+        )
+        .unwrap();
+
+        assert_eq!(stats.rows_processed, Some(3));
+        assert_eq!(parquet_row_count(&output_path).unwrap(), 3);
+
+        std::fs::remove_dir_all(&dir).ok();
+    }
+
+    #[test]
+    fn test_streaming_with_n_threads_and_low_memory() {
+        let dir = std::env::temp_dir().join("csv2parquet_test_streaming_threads_low_memory");
+        std::fs::create_dir_all(&dir).unwrap();
+        let input_path = dir.join("input.csv");
+        let output_path = dir.join("output.parquet");
+        std::fs::write(&input_path, "id,name\n1,alice\n2,bob\n3,carol\n").unwrap();
+
+        let options = ConversionOptions {
+            streaming: true,
+            n_threads: Some(1),
+            low_memory: true,
+            ..Default::default()
+        };
+        let stats = convert_csv_to_parquet(
+            &InputSource::Path(input_path),
+            &OutputSink::Path(output_path.clone()),
+            &options,
+        )
+        .unwrap();
+
+        // Checks that a streaming conversion with these options set still succeeds with
+        // unchanged content; whether the options take effect is not observable here
+        assert_eq!(stats.rows_processed, Some(3));
+
+        std::fs::remove_dir_all(&dir).ok();
+    }
 }
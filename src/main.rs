@@ -1,13 +1,16 @@
 use anyhow::{Context, Result};
 use clap::Parser;
-use csv2parquet::{convert_csv_to_parquet, Compression, ConversionOptions};
+use csv2parquet::{
+    convert_csv_to_parquet, Compression, ConversionOptions, CsvCompression, InputSource,
+    OutputSink,
+};
 use std::path::{Path, PathBuf};
 
 #[derive(Parser, Debug)]
 #[command(name = "csv2parquet")]
 #[command(version, about = "Convert CSV files to Parquet format", long_about = None)]
 struct Cli {
-    /// Input CSV file(s) to convert
+    /// Input CSV file(s) to convert (use `-` for stdin)
     #[arg(required = true, value_name = "FILES")]
     input_files: Vec<PathBuf>,
 
@@ -15,13 +18,13 @@ struct Cli {
     #[arg(short, long, value_name = "DIR")]
     output_dir: Option<PathBuf>,
 
-    /// Compression algorithm
-    #[arg(short, long, value_enum, default_value = "zstd")]
-    compression: CompressionType,
-
-    /// Compression level (algorithm-specific)
+    /// Write Parquet output to stdout instead of a file (requires exactly one input)
     #[arg(long)]
-    compression_level: Option<u32>,
+    stdout: bool,
+
+    /// Compression algorithm, optionally with a level, e.g. `zstd`, `zstd:9`, `gzip:6`
+    #[arg(short, long, default_value = "zstd", value_parser = parse_compression)]
+    compression: Compression,
 
     /// CSV has header row
     #[arg(long, default_value = "true")]
@@ -39,6 +42,14 @@ struct Cli {
     #[arg(long, default_value = "1000")]
     infer_schema_rows: usize,
 
+    /// Schema override file (`column_name: dtype` per line) for columns that should bypass inference
+    #[arg(long, value_name = "PATH")]
+    schema_file: Option<PathBuf>,
+
+    /// Compression codec of the input CSV itself (auto-detected from extension by default)
+    #[arg(long, value_enum, default_value = "auto")]
+    input_compression: InputCompressionArg,
+
     /// Row group size for Parquet
     #[arg(long, default_value = "500000")]
     row_group_size: usize,
@@ -51,6 +62,10 @@ struct Cli {
     #[arg(long)]
     low_memory: bool,
 
+    /// Stream the conversion instead of loading the whole file into memory (for larger-than-memory inputs)
+    #[arg(long)]
+    streaming: bool,
+
     /// Disable statistics in Parquet output
     #[arg(long)]
     no_statistics: bool,
@@ -58,37 +73,63 @@ struct Cli {
     /// Disable parallel writing
     #[arg(long)]
     no_parallel: bool,
+
+    /// Column to write a Parquet bloom filter for (repeatable)
+    #[arg(long = "bloom-filter", value_name = "COL")]
+    bloom_filter: Vec<String>,
+
+    /// False-positive probability for bloom filters, in (0, 1) exclusive (default 0.01)
+    #[arg(long)]
+    bloom_filter_fpp: Option<f64>,
+
+    /// Partition output Hive-style by this column's values (repeatable); writes a
+    /// directory of `key=value/` Parquet files under the output directory
+    #[arg(long = "partition-by", value_name = "COL")]
+    partition_by: Vec<String>,
 }
 
 #[derive(Debug, Clone, clap::ValueEnum)]
-enum CompressionType {
-    Uncompressed,
-    Snappy,
+enum InputCompressionArg {
+    Auto,
+    None,
     Gzip,
-    Lz4,
     Zstd,
-    Brotli,
 }
 
-impl CompressionType {
-    fn to_compression(&self, level: Option<u32>) -> Compression {
-        match self {
-            CompressionType::Uncompressed => Compression::Uncompressed,
-            CompressionType::Snappy => Compression::Snappy,
-            CompressionType::Gzip => Compression::Gzip(level.map(|l| l as u8)),
-            CompressionType::Lz4 => Compression::Lz4,
-            CompressionType::Zstd => Compression::Zstd(level.map(|l| l as i32)),
-            CompressionType::Brotli => Compression::Brotli(level),
+impl From<InputCompressionArg> for CsvCompression {
+    fn from(arg: InputCompressionArg) -> Self {
+        match arg {
+            InputCompressionArg::Auto => CsvCompression::Auto,
+            InputCompressionArg::None => CsvCompression::None,
+            InputCompressionArg::Gzip => CsvCompression::Gzip,
+            InputCompressionArg::Zstd => CsvCompression::Zstd,
         }
     }
 }
 
+fn parse_compression(spec: &str) -> std::result::Result<Compression, String> {
+    Compression::parse_spec(spec).map_err(|e| e.to_string())
+}
+
+/// `-` is the Unix convention for "read this input from stdin"
+fn is_stdin(path: &Path) -> bool {
+    path == Path::new("-")
+}
+
 fn main() -> Result<()> {
     let cli = Cli::parse();
 
     // Build conversion options from CLI arguments
     let options = build_conversion_options(&cli)?;
 
+    // Stdin can only be consumed once, and stdout can only carry one file's worth of output
+    if cli.input_files.iter().filter(|p| is_stdin(p)).count() > 1 {
+        anyhow::bail!("`-` (stdin) can only be used as a single input file");
+    }
+    if cli.stdout && cli.input_files.len() > 1 {
+        anyhow::bail!("--stdout requires exactly one input file");
+    }
+
     // Track statistics
     let mut total_files = 0;
     let mut successful = 0;
@@ -98,25 +139,58 @@ fn main() -> Result<()> {
     for input_file in &cli.input_files {
         total_files += 1;
 
-        // Determine output path
-        let output_path = determine_output_path(input_file, cli.output_dir.as_ref())?;
+        let label = if is_stdin(input_file) {
+            "<stdin>".to_string()
+        } else {
+            input_file.display().to_string()
+        };
+
+        let input = if is_stdin(input_file) {
+            InputSource::Stdin
+        } else {
+            InputSource::Path(input_file.clone())
+        };
+
+        let output = if cli.stdout {
+            OutputSink::Stdout
+        } else if !cli.partition_by.is_empty() {
+            OutputSink::Path(determine_partitioned_output_dir(
+                input_file,
+                cli.output_dir.as_ref(),
+            )?)
+        } else {
+            OutputSink::Path(determine_output_path(input_file, cli.output_dir.as_ref())?)
+        };
+        let output_label = match &output {
+            OutputSink::Path(path) => path.display().to_string(),
+            OutputSink::Stdout => "<stdout>".to_string(),
+        };
 
         // Perform conversion
-        match convert_csv_to_parquet(input_file, &output_path, &options) {
+        match convert_csv_to_parquet(&input, &output, &options) {
             Ok(stats) => {
                 successful += 1;
+                let rows = stats
+                    .rows_processed
+                    .map(|n| n.to_string())
+                    .unwrap_or_else(|| "?".to_string());
+                let partitions = stats
+                    .partitions_written
+                    .map(|n| format!(", {n} partitions"))
+                    .unwrap_or_default();
                 println!(
-                    "✓ {} -> {} ({} rows, {} bytes, {:.2}s)",
-                    input_file.display(),
-                    output_path.display(),
-                    stats.rows_processed,
+                    "✓ {} -> {} ({} rows, {} bytes{}, {:.2}s)",
+                    label,
+                    output_label,
+                    rows,
                     stats.output_size,
+                    partitions,
                     stats.duration.as_secs_f64()
                 );
             }
             Err(e) => {
                 failed += 1;
-                eprintln!("✗ {} - {}", input_file.display(), e);
+                eprintln!("✗ {} - {}", label, e);
             }
         }
     }
@@ -143,9 +217,6 @@ fn build_conversion_options(cli: &Cli) -> Result<ConversionOptions> {
     // Parse quote character
     let quote_char = parse_quote_char(&cli.quote_char).context("Invalid quote character")?;
 
-    // Convert compression type
-    let compression = cli.compression.to_compression(cli.compression_level);
-
     // Handle infer_schema_rows (0 means None)
     let infer_schema_rows = if cli.infer_schema_rows == 0 {
         None
@@ -165,12 +236,18 @@ fn build_conversion_options(cli: &Cli) -> Result<ConversionOptions> {
         delimiter,
         quote_char,
         infer_schema_rows,
-        compression,
+        schema_overrides: cli.schema_file.clone(),
+        input_compression: cli.input_compression.clone().into(),
+        compression: cli.compression,
         row_group_size: Some(cli.row_group_size),
         n_threads,
         low_memory: cli.low_memory,
         statistics: !cli.no_statistics,
         parallel: !cli.no_parallel,
+        streaming: cli.streaming,
+        bloom_filter_columns: cli.bloom_filter.clone(),
+        bloom_filter_fpp: cli.bloom_filter_fpp,
+        partition_by: cli.partition_by.clone(),
     })
 }
 
@@ -195,28 +272,48 @@ fn parse_quote_char(s: &str) -> Result<Option<u8>> {
     }
 }
 
-fn determine_output_path(input: &Path, output_dir: Option<&PathBuf>) -> Result<PathBuf> {
-    // Get the input filename without extension
-    let input_stem = input
+/// Resolve the directory and file stem (no extension) that output for `input` should be
+/// based on, shared by both the single-file and partitioned-dataset output paths
+fn output_base(input: &Path, output_dir: Option<&PathBuf>) -> Result<PathBuf> {
+    if is_stdin(input) {
+        // Stdin has no filename to derive a stem from
+        return Ok(match output_dir {
+            Some(dir) => dir.join("stdin"),
+            None => PathBuf::from("stdin"),
+        });
+    }
+
+    // Strip a compressed-CSV extension (e.g. `data.csv.gz`) before the `.csv` one,
+    // so `data.csv.gz` maps to `data.parquet` instead of `data.csv.parquet`
+    let de_compressed = match input.extension().and_then(|e| e.to_str()) {
+        Some("gz") | Some("zst") => input.with_extension(""),
+        _ => input.to_path_buf(),
+    };
+
+    let input_stem = de_compressed
         .file_stem()
         .context("Invalid input filename")?
         .to_str()
         .context("Filename is not valid UTF-8")?;
 
-    // Create output filename with .parquet extension
-    let output_filename = format!("{}.parquet", input_stem);
+    Ok(match output_dir {
+        Some(dir) => dir.join(input_stem),
+        None => match input.parent() {
+            Some(parent) => parent.join(input_stem),
+            None => PathBuf::from(input_stem),
+        },
+    })
+}
 
-    // Determine the output directory
-    let output_path = if let Some(dir) = output_dir {
-        dir.join(output_filename)
-    } else {
-        // Use input file's directory
-        if let Some(parent) = input.parent() {
-            parent.join(output_filename)
-        } else {
-            PathBuf::from(output_filename)
-        }
-    };
+fn determine_output_path(input: &Path, output_dir: Option<&PathBuf>) -> Result<PathBuf> {
+    // Append rather than `with_extension`, which would cut a dotted stem like `my.data`
+    let mut path = output_base(input, output_dir)?.into_os_string();
+    path.push(".parquet");
+    Ok(PathBuf::from(path))
+}
 
-    Ok(output_path)
+/// Base directory for a `--partition-by` dataset. Unlike the single-file case this must
+/// NOT carry a `.parquet` extension, since it's a directory of `key=value/` subdirectories
+fn determine_partitioned_output_dir(input: &Path, output_dir: Option<&PathBuf>) -> Result<PathBuf> {
+    output_base(input, output_dir)
 }